@@ -0,0 +1,90 @@
+// -*- coding: utf-8 -*-
+// ------------------------------------------------------------------------------------------------
+// Copyright © 2019, rs-reporting-api authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License.  You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied.  See the License for the specific language governing permissions and
+// limitations under the License.
+// ------------------------------------------------------------------------------------------------
+
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::Throughput;
+use reporting_api::BareReport;
+use reporting_api::Report;
+use reporting_api::NEL;
+use serde_json::json;
+
+fn nel_payload(count: usize) -> Vec<u8> {
+    let reports = (0..count)
+        .map(|i| {
+            json!({
+                "age": 500,
+                "type": "network-error",
+                "url": format!("https://example.com/page/{}", i),
+                "user_agent": "Mozilla/5.0",
+                "body": {
+                    "referrer": "https://example.com/",
+                    "sampling_fraction": 0.5,
+                    "server_ip": "203.0.113.75",
+                    "protocol": "h2",
+                    "method": "GET",
+                    "status_code": 200,
+                    "elapsed_time": 45,
+                    "phase": "application",
+                    "type": "ok"
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_vec(&reports).unwrap()
+}
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for count in &[1, 100, 1000] {
+        let payload = nel_payload(*count);
+        group.throughput(Throughput::Bytes(payload.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("from_str", count),
+            &payload,
+            |b, payload| {
+                b.iter(|| {
+                    let payload = std::str::from_utf8(payload).unwrap();
+                    serde_json::from_str::<Vec<BareReport>>(payload).unwrap()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("parse_reports", count),
+            &payload,
+            |b, payload| b.iter(|| reporting_api::parse_reports(payload).unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("parse_reports_nel", count),
+            &payload,
+            |b, payload| {
+                b.iter(|| {
+                    reporting_api::parse_reports(payload)
+                        .unwrap()
+                        .into_iter()
+                        .filter_map(BareReport::parse::<NEL>)
+                        .collect::<Result<Vec<Report<NEL>>, _>>()
+                        .unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);